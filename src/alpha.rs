@@ -63,7 +63,7 @@ mod tests {
 
     #[test]
     fn test_half_alpha() {
-        assert_abs_diff_eq!(get_alpha(1.0, (2.0 * std::f64::consts::PI).recip()), 0.5);
+        assert_abs_diff_eq!(get_alpha(1.0, (2.0 * core::f64::consts::PI).recip()), 0.5);
     }
 
     #[test]
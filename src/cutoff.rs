@@ -0,0 +1,46 @@
+use nalgebra::{RealField, SVector};
+
+/// A 1€ Filter cutoff parameter (`beta`, `mincutoff` or `dcutoff`).
+///
+/// Implemented for `T` itself, which shares one value across every signal
+/// component, and for `SVector<T, D>`, which gives each component its own
+/// value. This lets [`crate::state::OneEuroState::update`] accept either
+/// representation without forcing every caller onto the per-component form.
+pub trait Cutoff<T: RealField, const D: usize> {
+    /// Broadcast into a per-component vector.
+    fn into_vector(self) -> SVector<T, D>;
+}
+
+impl<T: RealField, const D: usize> Cutoff<T, D> for T {
+    #[inline]
+    fn into_vector(self) -> SVector<T, D> {
+        SVector::<T, D>::repeat(self)
+    }
+}
+
+impl<T: RealField, const D: usize> Cutoff<T, D> for SVector<T, D> {
+    #[inline]
+    fn into_vector(self) -> SVector<T, D> {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::Vector2;
+
+    use super::*;
+
+    #[test]
+    fn test_scalar_cutoff_broadcasts() {
+        let vector: Vector2<f64> = Cutoff::<f64, 2>::into_vector(1.5);
+        assert_abs_diff_eq!(vector, Vector2::new(1.5, 1.5));
+    }
+
+    #[test]
+    fn test_vector_cutoff_is_passed_through() {
+        let vector = Vector2::new(1.0, 2.0);
+        assert_abs_diff_eq!(vector.into_vector(), Vector2::new(1.0, 2.0));
+    }
+}
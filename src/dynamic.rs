@@ -0,0 +1,274 @@
+use core::ops::Neg;
+
+use nalgebra::{DVector, RealField};
+
+use crate::alpha::{get_alpha, get_alpha_unchecked};
+
+/// Low-pass filter state for a runtime-sized signal.
+///
+/// Same as [`crate::lowpass::LowPassState`] but backed by a [`DVector`]
+/// whose length is determined at runtime instead of a const generic.
+#[derive(Clone, Debug)]
+pub struct LowPassStateDyn<T: RealField>(DVector<T>);
+
+impl<T: RealField> LowPassStateDyn<T> {
+    /// Initialize low-pass filter state.
+    #[inline]
+    pub fn new(state: DVector<T>) -> Self {
+        Self(state)
+    }
+
+    /// Update state using [`filter`] function.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - new unfiltered signal
+    /// * `alpha` - smoothing factor
+    ///
+    /// # Panics
+    ///
+    /// See [`filter`].
+    #[inline]
+    pub fn update(&mut self, raw: &DVector<T>, alpha: &DVector<T>) {
+        self.0 = filter(raw, &self.0, alpha);
+    }
+
+    /// Same as [`LowPassStateDyn::update`] but without alpha check.
+    ///
+    /// # Safety
+    ///
+    /// See [`filter_unchecked`].
+    #[inline]
+    pub unsafe fn update_unchecked(&mut self, raw: &DVector<T>, alpha: &DVector<T>) {
+        self.0 = filter_unchecked(raw, &self.0, alpha);
+    }
+
+    /// Current state.
+    #[inline]
+    pub fn data(&self) -> &DVector<T> {
+        &self.0
+    }
+}
+
+impl<T: RealField> AsRef<DVector<T>> for LowPassStateDyn<T> {
+    #[inline]
+    fn as_ref(&self) -> &DVector<T> {
+        self.data()
+    }
+}
+
+impl<T: RealField> From<DVector<T>> for LowPassStateDyn<T> {
+    #[inline]
+    fn from(value: DVector<T>) -> Self {
+        LowPassStateDyn::new(value)
+    }
+}
+
+/// Same as [`crate::lowpass::filter`] but for runtime-sized vectors.
+///
+/// # Panics
+///
+/// This function will panic if any value in `alpha` is out of \(0, 1\] range.
+#[inline]
+pub fn filter<T: RealField>(
+    current: &DVector<T>,
+    previous: &DVector<T>,
+    alpha: &DVector<T>,
+) -> DVector<T> {
+    assert_alpha!(alpha);
+    unsafe { filter_unchecked(current, previous, alpha) }
+}
+
+/// Same as [`filter`] but without smoothing factor (`alpha`) check.
+///
+/// # Safety
+///
+/// Each value in `alpha` should be in \(0, 1\] range.
+#[inline]
+pub unsafe fn filter_unchecked<T: RealField>(
+    current: &DVector<T>,
+    previous: &DVector<T>,
+    alpha: &DVector<T>,
+) -> DVector<T> {
+    current.component_mul(alpha) + previous.component_mul(&(alpha.neg().add_scalar(T::one())))
+}
+
+/// 1€ Filter state for a runtime-sized signal.
+///
+/// Same as [`crate::state::OneEuroState`] but backed by [`DVector`] so the
+/// signal's dimensionality is chosen at runtime instead of fixed by a const
+/// generic `D`.
+#[derive(Clone, Debug)]
+pub struct OneEuroStateDyn<T: RealField> {
+    raw: DVector<T>,
+    filtered: LowPassStateDyn<T>,
+    derivate: LowPassStateDyn<T>,
+}
+
+impl<T: RealField> OneEuroStateDyn<T> {
+    /// Initializes 1€ Filter state.
+    #[inline]
+    pub fn new(state: DVector<T>) -> Self {
+        let derivate = DVector::<T>::zeros(state.len());
+        Self {
+            raw: state.clone(),
+            filtered: state.into(),
+            derivate: derivate.into(),
+        }
+    }
+
+    /// Current derivate.
+    #[inline]
+    fn derivate(&self) -> &DVector<T> {
+        self.derivate.as_ref()
+    }
+
+    /// Current state.
+    #[inline]
+    pub fn data(&self) -> &DVector<T> {
+        self.filtered.as_ref()
+    }
+
+    /// Current raw (not filtered) state.
+    #[inline]
+    pub fn raw(&self) -> &DVector<T> {
+        &self.raw
+    }
+
+    /// Calculate frequency cutoff:
+    ///
+    /// `intercept + slope * derivate`
+    ///
+    /// where `derivate` is value from [`derivate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `intercept` - minimal cutoff
+    /// * `slope` - cutoff coefficient
+    #[inline]
+    fn get_cutoff(&self, intercept: T, slope: T) -> DVector<T> {
+        self.derivate().abs().scale(slope).add_scalar(intercept)
+    }
+
+    /// Update state.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - new unfiltered signal
+    /// * `alpha` - smoothing factor for raw signal derivate
+    /// * `rate` - signal sampling frequency
+    /// * `mincutoff` - minimal value for frequency cutoff
+    /// * `beta` - slope for frequency cutoff
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    ///
+    /// * any value in `alpha` is not in \(0, 1\] range
+    /// * `rate` or `mincutoff` are negative or zero
+    /// * `beta` is negative
+    /// * `raw` or `alpha` length does not match the state's length
+    #[inline]
+    pub fn update(
+        &mut self,
+        raw: &DVector<T>,
+        alpha: &DVector<T>,
+        rate: T,
+        mincutoff: T,
+        beta: T,
+    ) {
+        self.derivate
+            .update(&(raw - &self.raw).scale(rate.clone()), alpha);
+
+        let alpha = self
+            .get_cutoff(mincutoff, beta)
+            .map(|v| get_alpha(rate.clone(), v));
+
+        // get_alpha is checked
+        unsafe { self.filtered.update_unchecked(raw, &alpha) }
+
+        self.raw = raw.clone();
+    }
+
+    /// Same as [`OneEuroStateDyn::update`] but without safety checks.
+    ///
+    /// # Safety
+    ///
+    /// Calculation is valid if:
+    ///
+    /// * each value in `alpha` is in \(0, 1\] range
+    /// * `rate` and `mincutoff` are positive
+    /// * `beta` is not negative
+    #[inline]
+    pub unsafe fn update_unchecked(
+        &mut self,
+        raw: &DVector<T>,
+        alpha: &DVector<T>,
+        rate: T,
+        mincutoff: T,
+        beta: T,
+    ) {
+        self.derivate
+            .update_unchecked(&(raw - &self.raw).scale(rate.clone()), alpha);
+
+        let alpha = self
+            .get_cutoff(mincutoff, beta)
+            .map(|v| get_alpha_unchecked(rate.clone(), v));
+
+        self.filtered.update_unchecked(raw, &alpha);
+
+        self.raw = raw.clone();
+    }
+}
+
+impl<T: RealField> AsRef<DVector<T>> for OneEuroStateDyn<T> {
+    #[inline]
+    fn as_ref(&self) -> &DVector<T> {
+        self.data()
+    }
+}
+
+impl<T: RealField> From<DVector<T>> for OneEuroStateDyn<T> {
+    #[inline]
+    fn from(value: DVector<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_state_dyn_update() {
+        let mut state = OneEuroStateDyn::new(DVector::from_vec(vec![1.0, 2.0]));
+
+        state.update(
+            &DVector::from_vec(vec![2.0, 3.0]),
+            &DVector::from_vec(vec![1.0, 1.0]),
+            1.0,
+            1.0,
+            0.0,
+        );
+
+        assert_abs_diff_eq!(state.raw(), &DVector::from_vec(vec![2.0, 3.0]));
+        assert!(state.data()[0] > 1.0 && state.data()[0] < 2.0);
+        assert!(state.data()[1] > 2.0 && state.data()[1] < 3.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_state_dyn_update_length_mismatch_panics() {
+        let mut state = OneEuroStateDyn::new(DVector::from_vec(vec![1.0, 2.0]));
+
+        state.update(
+            &DVector::from_vec(vec![2.0, 3.0, 4.0]),
+            &DVector::from_vec(vec![1.0, 1.0, 1.0]),
+            1.0,
+            1.0,
+            0.0,
+        );
+    }
+}
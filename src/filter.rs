@@ -1,10 +1,15 @@
+#[cfg(feature = "std")]
+use nalgebra::DVector;
 use nalgebra::{RealField, SVector};
 
-use crate::alpha::get_alpha_unchecked;
+use crate::alpha::{get_alpha, get_alpha_unchecked};
+#[cfg(feature = "std")]
+use crate::dynamic::OneEuroStateDyn;
 use crate::state::OneEuroState;
 
 /// 1€ Filter parameters.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OneEuroFilter<T: RealField> {
     beta: T,
     dcutoff: T,
@@ -15,19 +20,19 @@ impl<T: RealField> OneEuroFilter<T> {
     /// Slope for frequency cutoff.
     #[inline]
     pub fn beta(&self) -> T {
-        self.beta.to_owned()
+        self.beta.clone()
     }
 
     /// Derivative frequency cutoff.
     #[inline]
     pub fn dcutoff(&self) -> T {
-        self.dcutoff.to_owned()
+        self.dcutoff.clone()
     }
 
     /// Minimum value for frequency cutoff.
     #[inline]
     pub fn mincutoff(&self) -> T {
-        self.mincutoff.to_owned()
+        self.mincutoff.clone()
     }
 
     /// Set derivate frequency cutoff.
@@ -41,14 +46,14 @@ impl<T: RealField> OneEuroFilter<T> {
     #[inline]
     pub fn set_mincutoff(&mut self, value: T) {
         assert_positive!(value);
-        self.mincutoff = value.to_owned();
+        self.mincutoff = value.clone();
     }
 
     /// Set slope for frequency cutoff.
     #[inline]
     pub fn set_beta(&mut self, value: T) {
         assert!(value >= T::zero(), "beta should be zero or positive.");
-        self.beta = value.to_owned();
+        self.beta = value.clone();
     }
 
     /// Filter state using current parameters.
@@ -62,7 +67,7 @@ impl<T: RealField> OneEuroFilter<T> {
         unsafe {
             state.update_unchecked(
                 raw,
-                &self.get_alpha(rate.to_owned()),
+                &self.get_alpha(rate.clone()),
                 rate,
                 self.mincutoff(),
                 self.beta(),
@@ -78,11 +83,11 @@ impl<T: RealField> OneEuroFilter<T> {
         raws: &[SVector<T, D>],
         rate: T,
     ) {
-        let alpha = self.get_alpha::<D>(rate.to_owned());
+        let alpha = self.get_alpha::<D>(rate.clone());
 
         for (state, raw) in states.iter_mut().zip(raws) {
             unsafe {
-                state.update_unchecked(raw, &alpha, rate.to_owned(), self.mincutoff(), self.beta())
+                state.update_unchecked(raw, &alpha, rate.clone(), self.mincutoff(), self.beta())
             };
         }
     }
@@ -91,6 +96,64 @@ impl<T: RealField> OneEuroFilter<T> {
     pub fn get_alpha<const D: usize>(&self, rate: T) -> SVector<T, D> {
         SVector::<T, D>::repeat(unsafe { get_alpha_unchecked(rate, self.dcutoff()) })
     }
+
+    /// Same as [`OneEuroFilter::filter`] but for a runtime-sized signal.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn filter_dyn(&self, state: &mut OneEuroStateDyn<T>, raw: &DVector<T>, rate: T) {
+        unsafe {
+            state.update_unchecked(
+                raw,
+                &self.get_alpha_dyn(rate.clone(), raw.len()),
+                rate,
+                self.mincutoff(),
+                self.beta(),
+            )
+        };
+    }
+
+    /// Same as [`OneEuroFilter::get_alpha`] but for a runtime-sized signal of
+    /// length `len`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn get_alpha_dyn(&self, rate: T, len: usize) -> DVector<T> {
+        DVector::<T>::repeat(len, unsafe { get_alpha_unchecked(rate, self.dcutoff()) })
+    }
+
+    /// Same as [`OneEuroFilter::filter`], but `mincutoff`, `beta` and
+    /// `dcutoff` are given per-component instead of broadcasting the
+    /// parameters stored on this filter. Useful when a signal's components
+    /// have different noise characteristics, e.g. a 2D cursor where `x` is
+    /// noisier than `y`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if:
+    ///
+    /// * `rate` or any value of `mincutoff` or `dcutoff` are negative or zero
+    /// * any value of `beta` is negative
+    #[inline]
+    pub fn filter_with<const D: usize>(
+        &self,
+        state: &mut OneEuroState<T, D>,
+        raw: &SVector<T, D>,
+        rate: T,
+        mincutoff: SVector<T, D>,
+        beta: SVector<T, D>,
+        dcutoff: SVector<T, D>,
+    ) {
+        for value in mincutoff.iter() {
+            assert_positive!(*value, mincutoff);
+        }
+
+        for value in beta.iter() {
+            assert!(*value >= T::zero(), "beta should be zero or positive.");
+        }
+
+        let alpha = dcutoff.map(|v| get_alpha(rate.clone(), v));
+
+        unsafe { state.update_unchecked(raw, &alpha, rate, mincutoff, beta) };
+    }
 }
 
 impl<T: RealField> Default for OneEuroFilter<T> {
@@ -104,3 +167,23 @@ impl<T: RealField> Default for OneEuroFilter<T> {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_serde_roundtrip() {
+        let mut filter = OneEuroFilter::<f64>::default();
+        filter.set_beta(0.5);
+        filter.set_dcutoff(2.0);
+        filter.set_mincutoff(0.25);
+
+        let json = serde_json::to_string(&filter).unwrap();
+        let restored: OneEuroFilter<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.beta(), filter.beta());
+        assert_eq!(restored.dcutoff(), filter.dcutoff());
+        assert_eq!(restored.mincutoff(), filter.mincutoff());
+    }
+}
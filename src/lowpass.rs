@@ -1,9 +1,10 @@
-use std::ops::Neg;
+use core::ops::Neg;
 
 use nalgebra::{RealField, SVector};
 
 /// Low-pass filter state.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LowPassState<T: RealField, const D: usize> (SVector<T, D>);
 
 impl<T: RealField, const D: usize> LowPassState<T, D> {
@@ -2,9 +2,11 @@ use nalgebra::{RealField, SVector};
 
 use crate::lowpass::LowPassState;
 use crate::alpha::{get_alpha, get_alpha_unchecked};
+use crate::cutoff::Cutoff;
 
 /// 1€ Filter state.
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OneEuroState<T: RealField, const D: usize> {
     raw: SVector<T, D>,
     filtered: LowPassState<T, D>,
@@ -16,8 +18,8 @@ impl<T: RealField, const D: usize> OneEuroState<T, D> {
     #[inline]
     pub fn new(state: SVector<T, D>) -> Self {
         Self {
-            raw: state.to_owned(),
-            filtered: state.to_owned().into(),
+            raw: state.clone(),
+            filtered: state.clone().into(),
             derivate: SVector::<T, D>::zeros().into(),
         }
     }
@@ -47,51 +49,55 @@ impl<T: RealField, const D: usize> OneEuroState<T, D> {
     /// where `derivate` is value from [`derivate`].
     ///
     /// # Arguments
-    ///     
-    /// * `intercept` - minimal cutoff
-    /// * `slope` - cutoff coefficient
+    ///
+    /// * `intercept` - minimal cutoff, per-component
+    /// * `slope` - cutoff coefficient, per-component
     #[inline]
-    fn get_cutoff(&self, intercept: T, slope: T) -> SVector<T, D> {
-        self.derivate().abs().scale(slope).add_scalar(intercept)
+    fn get_cutoff(&self, intercept: SVector<T, D>, slope: SVector<T, D>) -> SVector<T, D> {
+        self.derivate().abs().component_mul(&slope) + intercept
     }
 
     /// Update state.
     ///
+    /// `mincutoff` and `beta` may each be either `T`, applying the same
+    /// value to every component, or `SVector<T, D>`, giving every component
+    /// its own value (see [`Cutoff`]).
+    ///
     /// # Arguments
     ///
     /// * `raw` - new unfiltered signal
     /// * `alpha` - smoothing factor for raw signal derivate
     /// * `rate` - signal sampling frequency
-    /// * `mincutoff` - minimal value for frequency cutoff
-    /// * `beta` - slope for frequency cutoff
+    /// * `mincutoff` - minimal value(s) for frequency cutoff
+    /// * `beta` - slope(s) for frequency cutoff
     ///
     /// # Panics
     ///
     /// This function panics if:
     ///
     /// * any value in `alpha` is not in \(0, 1\] range
-    /// * `rate` or `mincutoff` are negative or zero
-    /// * `beta` is negative
+    /// * `rate` or any value of `mincutoff` are negative or zero
+    /// * any value of `beta` is negative
     #[inline]
-    pub fn update(
+    pub fn update<M: Cutoff<T, D>, B: Cutoff<T, D>>(
         &mut self,
         raw: &SVector<T, D>,
         alpha: &SVector<T, D>,
         rate: T,
-        mincutoff: T,
-        beta: T,
+        mincutoff: M,
+        beta: B,
     ) {
         self.derivate
-            .update(&(raw - &self.raw).scale(rate.to_owned()), alpha);
+            .update(&(raw - &self.raw).scale(rate.clone()), alpha);
 
         let alpha = self
-            .get_cutoff(mincutoff, beta)
-            .map(|v| get_alpha(rate.to_owned(), v));
+            .get_cutoff(mincutoff.into_vector(), beta.into_vector())
+            .map(|v| get_alpha(rate.clone(), v));
 
         // get_alpha is checked
         unsafe { self.filtered.update_unchecked(raw, &alpha) }
 
-        self.raw = raw.to_owned();
+        self.raw = raw.clone();
     }
 
     /// Same as [`OneEuroState::update`] but without safety checks.
@@ -101,27 +107,27 @@ impl<T: RealField, const D: usize> OneEuroState<T, D> {
     /// Calculation is valid if:
     ///
     /// * each value in `alpha` is in \(0, 1\] range
-    /// * `rate` and `mincutoff` are positive
-    /// * `beta` is not negative
+    /// * `rate` and each value of `mincutoff` are positive
+    /// * each value of `beta` is not negative
     #[inline]
-    pub unsafe fn update_unchecked(
+    pub unsafe fn update_unchecked<M: Cutoff<T, D>, B: Cutoff<T, D>>(
         &mut self,
         raw: &SVector<T, D>,
         alpha: &SVector<T, D>,
         rate: T,
-        mincutoff: T,
-        beta: T,
+        mincutoff: M,
+        beta: B,
     ) {
         self.derivate
-            .update_unchecked(&(raw - &self.raw).scale(rate.to_owned()), alpha);
+            .update_unchecked(&(raw - &self.raw).scale(rate.clone()), alpha);
 
         let alpha = self
-            .get_cutoff(mincutoff, beta)
-            .map(|v| get_alpha_unchecked(rate.to_owned(), v));
+            .get_cutoff(mincutoff.into_vector(), beta.into_vector())
+            .map(|v| get_alpha_unchecked(rate.clone(), v));
 
         self.filtered.update_unchecked(raw, &alpha);
 
-        self.raw = raw.to_owned();
+        self.raw = raw.clone();
     }
 }
 
@@ -152,3 +158,62 @@ impl<T: RealField, const D: usize> From<[T; D]> for OneEuroState<T, D> {
         Self::new(value.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_update_with_scalar_and_per_component_cutoff_agree() {
+        let mut scalar_state = OneEuroState::<f64, 2>::new([0.0, 0.0].into());
+        let mut vector_state = OneEuroState::<f64, 2>::new([0.0, 0.0].into());
+
+        let raw = [1.0, 1.0].into();
+        let alpha = [1.0, 1.0].into();
+
+        scalar_state.update(&raw, &alpha, 1.0, 1.0, 0.0);
+        vector_state.update(
+            &raw,
+            &alpha,
+            1.0,
+            SVector::<f64, 2>::new(1.0, 1.0),
+            SVector::<f64, 2>::new(0.0, 0.0),
+        );
+
+        assert_abs_diff_eq!(scalar_state.data(), vector_state.data());
+    }
+
+    #[test]
+    fn test_update_per_component_cutoff_differs_per_component() {
+        let mut state = OneEuroState::<f64, 2>::new([0.0, 0.0].into());
+
+        state.update(
+            &[1.0, 1.0].into(),
+            &[1.0, 1.0].into(),
+            1.0,
+            SVector::<f64, 2>::new(1.0, 100.0),
+            SVector::<f64, 2>::new(0.0, 0.0),
+        );
+
+        assert!(state.data().x != state.data().y);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_state_serde_roundtrip() {
+        let mut state = OneEuroState::<f64, 2>::new([1.0, 2.0].into());
+        state.update(&[1.5, 2.5].into(), &[0.5, 0.5].into(), 1.0, 1.0, 0.0);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: OneEuroState<f64, 2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.data(), state.data());
+        assert_eq!(restored.raw(), state.raw());
+    }
+}
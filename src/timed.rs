@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use nalgebra::{convert, RealField, SVector};
+use simba::scalar::SupersetOf;
+
+use crate::filter::OneEuroFilter;
+use crate::state::OneEuroState;
+
+/// 1€ Filter state tracking the timestamp of the previously filtered sample.
+///
+/// Pairs with [`OneEuroFilter::filter_at`] to derive the sampling rate from
+/// elapsed wall-clock time instead of requiring the caller to track a
+/// previous timestamp and compute the rate by hand.
+#[derive(Clone, Copy, Debug)]
+pub struct TimedOneEuroState<T: RealField, const D: usize> {
+    state: OneEuroState<T, D>,
+    last: Option<Duration>,
+}
+
+impl<T: RealField, const D: usize> TimedOneEuroState<T, D> {
+    /// Initializes timestamped 1€ Filter state.
+    #[inline]
+    pub fn new(state: SVector<T, D>) -> Self {
+        Self {
+            state: state.into(),
+            last: None,
+        }
+    }
+
+    /// Current state.
+    #[inline]
+    pub fn data(&self) -> &SVector<T, D> {
+        self.state.data()
+    }
+
+    /// Current raw (not filtered) state.
+    #[inline]
+    pub fn raw(&self) -> &SVector<T, D> {
+        self.state.raw()
+    }
+
+    /// Timestamp this state was last advanced with, if any. Set by the
+    /// first [`OneEuroFilter::filter_at`] call (which only primes `state`
+    /// without filtering) and by every later call whose timestamp is
+    /// strictly greater than this one. A repeated or out-of-order
+    /// timestamp doesn't advance it, so the next in-order sample still
+    /// derives its rate against the correct reference point.
+    #[inline]
+    pub fn timestamp(&self) -> Option<Duration> {
+        self.last
+    }
+}
+
+impl<T: RealField, const D: usize> AsRef<SVector<T, D>> for TimedOneEuroState<T, D> {
+    #[inline]
+    fn as_ref(&self) -> &SVector<T, D> {
+        self.data()
+    }
+}
+
+impl<T: RealField, const D: usize> From<SVector<T, D>> for TimedOneEuroState<T, D> {
+    #[inline]
+    fn from(value: SVector<T, D>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: RealField> OneEuroFilter<T> {
+    /// Filter state deriving the sampling rate from elapsed wall-clock time
+    /// instead of a precomputed `rate`.
+    ///
+    /// The rate is derived as `1 / (timestamp - previous timestamp)`. The
+    /// first sample has no previous timestamp to derive a rate from, so it
+    /// is passed through as-is and only primes `state` with `timestamp`. A
+    /// repeated or out-of-order timestamp (non-positive elapsed duration)
+    /// has no rate to derive either, so `state` is left untouched entirely:
+    /// neither the accumulated filtered value nor `timestamp` advance, so
+    /// the next in-order sample still derives its rate against the last
+    /// timestamp a rate was actually computed from.
+    pub fn filter_at<const D: usize>(
+        &self,
+        state: &mut TimedOneEuroState<T, D>,
+        raw: &SVector<T, D>,
+        timestamp: Duration,
+    ) where
+        T: SupersetOf<f64>,
+    {
+        match state.last {
+            None => {
+                state.state = OneEuroState::new(raw.to_owned());
+                state.last = Some(timestamp);
+            }
+            Some(last) if timestamp > last => {
+                let elapsed = (timestamp - last).as_secs_f64();
+                let rate = convert::<f64, T>(elapsed).recip();
+                self.filter(&mut state.state, raw, rate);
+                state.last = Some(timestamp);
+            }
+            Some(_) => {
+                // Repeated or out-of-order timestamp: no rate can be
+                // derived, so leave `state` (including `last`) untouched.
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+    use nalgebra::Vector1;
+
+    use super::*;
+
+    #[test]
+    fn test_filter_at_first_sample_passes_through() {
+        let filter = OneEuroFilter::default();
+        let mut state = TimedOneEuroState::new(Vector1::new(1.0));
+
+        filter.filter_at(&mut state, &Vector1::new(2.0), Duration::from_secs(1));
+
+        assert_abs_diff_eq!(state.data(), &Vector1::new(2.0));
+        assert_eq!(state.timestamp(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_filter_at_duplicate_timestamp_does_not_reset_state() {
+        let filter = OneEuroFilter::default();
+        let mut state = TimedOneEuroState::new(Vector1::new(1.0));
+
+        filter.filter_at(&mut state, &Vector1::new(2.0), Duration::from_secs(1));
+        filter.filter_at(&mut state, &Vector1::new(5.0), Duration::from_millis(1500));
+        let before = *state.data();
+
+        filter.filter_at(&mut state, &Vector1::new(9.0), Duration::from_millis(1500));
+
+        assert_abs_diff_eq!(state.data(), &before);
+        assert_eq!(state.timestamp(), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn test_filter_at_derives_rate_from_elapsed_time() {
+        let filter = OneEuroFilter::default();
+        let mut state = TimedOneEuroState::new(Vector1::new(0.0));
+
+        filter.filter_at(&mut state, &Vector1::new(0.0), Duration::from_secs(1));
+        filter.filter_at(&mut state, &Vector1::new(1.0), Duration::from_secs(2));
+
+        assert!(state.data().x > 0.0 && state.data().x < 1.0);
+        assert_eq!(state.timestamp(), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_filter_at_out_of_order_timestamp_does_not_corrupt_rate_reference() {
+        let filter = OneEuroFilter::default();
+
+        // An out-of-order sample between two in-order ones must be a pure
+        // no-op: the next in-order sample should derive its rate against
+        // the last timestamp actually used, as if the stray sample was
+        // never passed in at all.
+        let mut reordered = TimedOneEuroState::new(Vector1::new(0.0));
+        filter.filter_at(&mut reordered, &Vector1::new(0.0), Duration::from_secs(0));
+        filter.filter_at(&mut reordered, &Vector1::new(2.0), Duration::from_secs(5));
+        filter.filter_at(&mut reordered, &Vector1::new(99.0), Duration::from_secs(3));
+        filter.filter_at(&mut reordered, &Vector1::new(4.0), Duration::from_secs(6));
+
+        let mut reference = TimedOneEuroState::new(Vector1::new(0.0));
+        filter.filter_at(&mut reference, &Vector1::new(0.0), Duration::from_secs(0));
+        filter.filter_at(&mut reference, &Vector1::new(2.0), Duration::from_secs(5));
+        filter.filter_at(&mut reference, &Vector1::new(4.0), Duration::from_secs(6));
+
+        assert_abs_diff_eq!(reordered.data(), reference.data());
+        assert_eq!(reordered.timestamp(), reference.timestamp());
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 #[macro_use]
 extern crate approx;
 
@@ -7,7 +9,7 @@ mod tests {
     use std::{fs::File, time::Duration, fmt::Debug};
 
     use nalgebra::Point2;
-    use one_euro::{OneEuroState, OneEuroFilter};
+    use one_euro::{OneEuroFilter, TimedOneEuroState};
     use serde::Deserialize;
 
     #[derive(Debug)]
@@ -56,23 +58,20 @@ mod tests {
 
         let mut records = reader.deserialize::<Record>();
 
-        let (mut timestamp, mut state) = records.next().map(|r| {
+        let (first_timestamp, first_raw) = records.next().map(|r| {
             let record: Record = r.expect("Error parsing test entry.");
             let entry = Entry::from(record);
-            let state: OneEuroState<f64, 2> = entry.noisy.coords.into();
-            let timestamp = entry.timestamp;
-            (timestamp, state)
+            (entry.timestamp, entry.noisy.coords)
         }).unwrap();
 
+        let mut state = TimedOneEuroState::new(first_raw);
+        filter.filter_at(&mut state, &first_raw, first_timestamp);
+
         for result in records {
             let record: Record = result.expect("Error parsing test entry.");
             let entry = Entry::from(record);
 
-            let rate = (entry.timestamp - timestamp).as_secs_f64().recip();
-
-            filter.filter(&mut state, &entry.noisy.coords, rate);
-
-            timestamp = entry.timestamp;
+            filter.filter_at(&mut state, &entry.noisy.coords, entry.timestamp);
 
             assert_abs_diff_eq!(entry.filtered.coords, state.data(), epsilon = 1e-6);
         }